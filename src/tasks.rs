@@ -1,4 +1,8 @@
-use chrono::NaiveDateTime;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use chrono::{Duration, Local, NaiveDateTime};
 use colored::*;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +26,28 @@ pub struct Task {
     pub when: Option<NaiveDateTime>,     // The date you want to do the task
     pub deadline: Option<NaiveDateTime>, // The latest date the task should be done
     pub reminder: Option<NaiveDateTime>, // The datetime a reminder will alert you
+    pub recurrence: Option<Recurrence>,  // How often the task repeats once completed
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Recurrence {
+    Daily(u32),
+    Weekly(u32),
+    Monthly(u32),
+}
+
+impl Recurrence {
+    /// Advances `datetime` by one occurrence, returning `None` if doing so
+    /// would overflow `NaiveDateTime`'s range instead of panicking.
+    fn advance(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let interval = |n: u32| i64::from(n.max(1));
+
+        match self {
+            Recurrence::Daily(n) => datetime.checked_add_signed(Duration::days(interval(*n))),
+            Recurrence::Weekly(n) => datetime.checked_add_signed(Duration::weeks(interval(*n))),
+            Recurrence::Monthly(n) => datetime.checked_add_months(chrono::Months::new((*n).max(1))),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -31,6 +57,86 @@ pub struct Tasks {
     pub tasks: Option<Vec<Task>>, // All the tasks in one vector
 }
 
+const HISTORY_FILE: &str = ".agenda_history.json";
+const HISTORY_LIMIT: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct History {
+    past: Vec<Option<Vec<Task>>>,
+    future: Vec<Option<Vec<Task>>>,
+}
+
+const CONFIG_DIR: &str = "agenda";
+const CONFIG_FILE: &str = "config.toml";
+
+/// User-configurable settings for where the agenda lives and how new tasks
+/// behave, loaded from a TOML file in the user's config directory.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Config {
+    pub repo_path: String,      // Path to the tasks repository
+    pub tasks_file: String,     // Name of the tasks file inside the repository
+    pub remote: String,         // Default git remote used by `Tasks::sync`
+    pub default_status: Status, // Status new tasks start in when left unset
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            repo_path: default_repo_path(),
+            tasks_file: String::from("tasks.json"),
+            remote: String::from("origin"),
+            default_status: Status::Inbox,
+        }
+    }
+}
+
+fn default_repo_path() -> String {
+    let home = dirs::home_dir().unwrap_or_else(|| Path::new(".").to_path_buf());
+    home.join(".agenda").to_string_lossy().into_owned()
+}
+
+fn config_path() -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_DIR).join(CONFIG_FILE))
+}
+
+impl Config {
+    /// Loads the config from the user's config directory, falling back to
+    /// [`Config::default`] when the file is absent.
+    pub fn load() -> Result<Self, TasksError> {
+        let path = match config_path() {
+            Some(path) => path,
+            None => return Ok(Self::default()),
+        };
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(io_error)?;
+        toml::from_str(&contents)
+            .map_err(|err| TasksError(format!("couldn't parse config: {}", err)))
+    }
+
+    /// Builds a task the same as [`Task::new`], but uses this config's
+    /// `default_status` in place of `Status::Inbox` when `when` is unset.
+    pub fn new_task(
+        &self,
+        title: String,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+        when: Option<NaiveDateTime>,
+        deadline: Option<NaiveDateTime>,
+        reminder: Option<NaiveDateTime>,
+    ) -> Task {
+        let mut task = Task::new(title, notes, tags, when, deadline, reminder);
+        if task.when.is_none() {
+            task.status = self.default_status.clone();
+        }
+
+        task
+    }
+}
+
 fn task_not_found(id: usize) -> TasksError {
     TasksError(format!("couldn't find task with id {}", id))
 }
@@ -39,6 +145,37 @@ fn no_tasks_available() -> TasksError {
     TasksError(String::from("no tasks available"))
 }
 
+fn no_history_available() -> TasksError {
+    TasksError(String::from("nothing to undo"))
+}
+
+fn no_future_available() -> TasksError {
+    TasksError(String::from("nothing to redo"))
+}
+
+fn io_error(err: std::io::Error) -> TasksError {
+    TasksError(format!("couldn't access tasks file: {}", err))
+}
+
+fn serde_error(err: serde_json::Error) -> TasksError {
+    TasksError(format!("couldn't parse tasks file: {}", err))
+}
+
+/// Parses fuzzy human input like "tomorrow 5pm" or "in 3 days" into a
+/// `NaiveDateTime`, relative to the current local time.
+fn parse_datetime(input: &str) -> Result<NaiveDateTime, TasksError> {
+    fuzzydate::parse(input)
+        .map_err(|err| TasksError(format!("couldn't understand date {:?}: {}", input, err)))
+}
+
+fn git_error(action: &str, output: &Output) -> TasksError {
+    TasksError(format!(
+        "git {} failed: {}",
+        action,
+        String::from_utf8_lossy(&output.stderr).trim()
+    ))
+}
+
 impl Task {
     pub fn new(
         title: String,
@@ -62,9 +199,16 @@ impl Task {
             when,
             deadline,
             reminder,
+            recurrence: None,
         }
     }
 
+    /// Sets how often this task repeats once completed, or `None` to make
+    /// it a one-off.
+    pub fn set_recurrence(&mut self, recurrence: Option<Recurrence>) {
+        self.recurrence = recurrence;
+    }
+
     pub fn modify(
         &mut self,
         title: Option<String>,
@@ -99,6 +243,45 @@ impl Task {
         };
     }
 
+    /// Builds a task the same as [`Task::new`], but accepts `when`,
+    /// `deadline` and `reminder` as fuzzy human input (e.g. "next friday")
+    /// instead of already-parsed `NaiveDateTime`s.
+    pub fn new_natural(
+        title: String,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+        when: Option<&str>,
+        deadline: Option<&str>,
+        reminder: Option<&str>,
+    ) -> Result<Self, TasksError> {
+        let when = when.map(parse_datetime).transpose()?;
+        let deadline = deadline.map(parse_datetime).transpose()?;
+        let reminder = reminder.map(parse_datetime).transpose()?;
+
+        Ok(Self::new(title, notes, tags, when, deadline, reminder))
+    }
+
+    /// Modifies a task the same as [`Task::modify`], but accepts `when`,
+    /// `deadline` and `reminder` as fuzzy human input instead of
+    /// already-parsed `NaiveDateTime`s.
+    pub fn modify_natural(
+        &mut self,
+        title: Option<String>,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+        when: Option<&str>,
+        deadline: Option<&str>,
+        reminder: Option<&str>,
+    ) -> Result<(), TasksError> {
+        let when = when.map(parse_datetime).transpose()?;
+        let deadline = deadline.map(parse_datetime).transpose()?;
+        let reminder = reminder.map(parse_datetime).transpose()?;
+
+        self.modify(title, notes, tags, when, deadline, reminder);
+
+        Ok(())
+    }
+
     pub fn start(&mut self) {
         self.status = Status::Active;
     }
@@ -114,6 +297,35 @@ impl Task {
     pub fn complete(&mut self) {
         self.status = Status::Complete;
     }
+
+    /// Builds the next occurrence of a recurring task: `when`, `deadline`
+    /// and `reminder` are each advanced past any missed occurrences to the
+    /// next future date, and status is reset as in [`Task::new`].
+    fn next_occurrence(&self, recurrence: &Recurrence) -> Self {
+        let now = Local::now().naive_local();
+        let advance_past_now = |datetime: NaiveDateTime| {
+            let mut next = datetime;
+            while next <= now {
+                match recurrence.advance(next) {
+                    Some(advanced) => next = advanced,
+                    None => break,
+                }
+            }
+            next
+        };
+
+        let mut next = self.clone();
+        next.when = self.when.map(advance_past_now);
+        next.deadline = self.deadline.map(advance_past_now);
+        next.reminder = self.reminder.map(advance_past_now);
+        next.status = if next.when.is_some() {
+            Status::Pending
+        } else {
+            Status::Inbox
+        };
+
+        next
+    }
 }
 
 impl Tasks {
@@ -125,6 +337,264 @@ impl Tasks {
         }
     }
 
+    /// Builds a `Tasks` for the repository path and file named in `config`.
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(&config.repo_path, &config.tasks_file)
+    }
+
+    /// Loads the user's config and the task vector it points at in one step.
+    pub fn load_from_config() -> Result<Self, TasksError> {
+        let config = Config::load()?;
+        Self::load(&config.repo_path, &config.tasks_file)
+    }
+
+    /// Loads the task vector from `tasks_file` inside `repo_path`, treating a
+    /// missing file as an empty agenda rather than an error.
+    pub fn load(repo_path: &str, tasks_file: &str) -> Result<Self, TasksError> {
+        let mut tasks = Self::new(repo_path, tasks_file);
+        let path = Path::new(repo_path).join(tasks_file);
+
+        if !path.exists() {
+            return Ok(tasks);
+        }
+
+        let contents = fs::read_to_string(path).map_err(io_error)?;
+        let parsed: Vec<Task> = serde_json::from_str(&contents).map_err(serde_error)?;
+        tasks.tasks = if parsed.is_empty() {
+            None
+        } else {
+            Some(parsed)
+        };
+
+        Ok(tasks)
+    }
+
+    /// Writes the task vector to `file` inside `path`, creating the repository
+    /// directory if it doesn't already exist.
+    pub fn save(&self) -> Result<(), TasksError> {
+        self.ensure_repo_dir()?;
+
+        let contents = serde_json::to_string_pretty(self.tasks.as_deref().unwrap_or_default())
+            .map_err(serde_error)?;
+
+        fs::write(Path::new(&self.path).join(&self.file), contents).map_err(io_error)
+    }
+
+    /// Creates the repository directory at `path` if it doesn't already
+    /// exist, so writes to the tasks or history file never fail on a
+    /// brand-new install.
+    fn ensure_repo_dir(&self) -> Result<(), TasksError> {
+        fs::create_dir_all(&self.path).map_err(io_error)
+    }
+
+    fn run_git(&self, args: &[&str]) -> Result<Output, TasksError> {
+        Command::new("git")
+            .args(args)
+            .current_dir(&self.path)
+            .output()
+            .map_err(io_error)
+    }
+
+    /// Saves the task vector and commits it to the repository at `path`,
+    /// no-op'ing when there's nothing new to commit.
+    fn commit(&self, message: &str) -> Result<(), TasksError> {
+        self.save()?;
+
+        let add = self.run_git(&["add", &self.file])?;
+        if !add.status.success() {
+            return Err(git_error("add", &add));
+        }
+
+        let commit = self.run_git(&["commit", "-m", message])?;
+        if !commit.status.success()
+            && !String::from_utf8_lossy(&commit.stdout).contains("nothing to commit")
+        {
+            return Err(git_error("commit", &commit));
+        }
+
+        Ok(())
+    }
+
+    /// Synchronises the agenda repository with `remote`, rebasing onto any
+    /// upstream changes before pushing local commits.
+    pub fn sync(&self, remote: &str) -> Result<(), TasksError> {
+        let pull = self.run_git(&["pull", "--rebase", remote])?;
+        if !pull.status.success() {
+            return Err(git_error("pull --rebase", &pull));
+        }
+
+        let push = self.run_git(&["push", remote])?;
+        if !push.status.success() {
+            return Err(git_error("push", &push));
+        }
+
+        Ok(())
+    }
+
+    /// Synchronises the agenda repository using `config.remote` in place of
+    /// an explicit remote name. See [`Tasks::sync`].
+    pub fn sync_with_config(&self, config: &Config) -> Result<(), TasksError> {
+        self.sync(&config.remote)
+    }
+
+    fn history_path(&self) -> std::path::PathBuf {
+        Path::new(&self.path).join(HISTORY_FILE)
+    }
+
+    fn load_history(&self) -> Result<History, TasksError> {
+        let path = self.history_path();
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(io_error)?;
+        serde_json::from_str(&contents).map_err(serde_error)
+    }
+
+    fn save_history(&self, history: &History) -> Result<(), TasksError> {
+        self.ensure_repo_dir()?;
+
+        let contents = serde_json::to_string_pretty(history).map_err(serde_error)?;
+        fs::write(self.history_path(), contents).map_err(io_error)
+    }
+
+    /// Records the current task vector as an undo point, capping the stack
+    /// at `HISTORY_LIMIT` entries and discarding any redo history.
+    fn snapshot(&mut self) -> Result<(), TasksError> {
+        let mut history = self.load_history()?;
+
+        history.past.push(self.tasks.clone());
+        if history.past.len() > HISTORY_LIMIT {
+            history.past.remove(0);
+        }
+        history.future.clear();
+
+        self.save_history(&history)
+    }
+
+    /// Reverts the last `times` mutating operations, restoring the task
+    /// vector to how it looked before each one.
+    pub fn undo(&mut self, times: usize) -> Result<(), TasksError> {
+        let mut history = self.load_history()?;
+
+        if history.past.is_empty() {
+            return Err(no_history_available());
+        }
+
+        for _ in 0..times {
+            let snapshot = match history.past.pop() {
+                Some(snapshot) => snapshot,
+                None => break,
+            };
+
+            history.future.push(self.tasks.clone());
+            self.tasks = snapshot;
+        }
+
+        self.save_history(&history)?;
+        self.commit("Undo")
+    }
+
+    /// Re-applies the last `times` operations undone with [`Tasks::undo`].
+    pub fn redo(&mut self, times: usize) -> Result<(), TasksError> {
+        let mut history = self.load_history()?;
+
+        if history.future.is_empty() {
+            return Err(no_future_available());
+        }
+
+        for _ in 0..times {
+            let snapshot = match history.future.pop() {
+                Some(snapshot) => snapshot,
+                None => break,
+            };
+
+            history.past.push(self.tasks.clone());
+            self.tasks = snapshot;
+        }
+
+        self.save_history(&history)?;
+        self.commit("Redo")
+    }
+
+    /// Modifies the task at `id`, recording an undo point beforehand.
+    #[allow(clippy::too_many_arguments)]
+    pub fn modify_task(
+        &mut self,
+        id: usize,
+        title: Option<String>,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+        when: Option<NaiveDateTime>,
+        deadline: Option<NaiveDateTime>,
+        reminder: Option<NaiveDateTime>,
+    ) -> Result<(), TasksError> {
+        self.get_task(id)?;
+        self.snapshot()?;
+        self.get_task(id)
+            .unwrap()
+            .modify(title, notes, tags, when, deadline, reminder);
+
+        self.commit(&format!("Modify task {}", id))
+    }
+
+    /// Modifies the task at `id` from fuzzy human date input, recording an
+    /// undo point beforehand. See [`Task::modify_natural`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn modify_task_natural(
+        &mut self,
+        id: usize,
+        title: Option<String>,
+        notes: Option<String>,
+        tags: Option<Vec<String>>,
+        when: Option<&str>,
+        deadline: Option<&str>,
+        reminder: Option<&str>,
+    ) -> Result<(), TasksError> {
+        let when = when.map(parse_datetime).transpose()?;
+        let deadline = deadline.map(parse_datetime).transpose()?;
+        let reminder = reminder.map(parse_datetime).transpose()?;
+
+        self.modify_task(id, title, notes, tags, when, deadline, reminder)
+    }
+
+    /// Starts the task at `id`, recording an undo point beforehand.
+    pub fn start_task(&mut self, id: usize) -> Result<(), TasksError> {
+        self.get_task(id)?;
+        self.snapshot()?;
+        self.get_task(id).unwrap().start();
+
+        self.commit(&format!("Start task {}", id))
+    }
+
+    /// Stops the task at `id`, recording an undo point beforehand.
+    pub fn stop_task(&mut self, id: usize) -> Result<(), TasksError> {
+        self.get_task(id)?;
+        self.snapshot()?;
+        self.get_task(id).unwrap().stop();
+
+        self.commit(&format!("Stop task {}", id))
+    }
+
+    /// Completes the task at `id`, recording an undo point beforehand.
+    pub fn complete_task(&mut self, id: usize) -> Result<(), TasksError> {
+        self.get_task(id)?;
+        self.snapshot()?;
+
+        let task = self.get_task(id).unwrap();
+        let next = task
+            .recurrence
+            .clone()
+            .map(|recurrence| task.next_occurrence(&recurrence));
+        task.complete();
+
+        if let Some(next) = next {
+            self.tasks.as_mut().unwrap().push(next);
+        }
+
+        self.commit(&format!("Complete task {}", id))
+    }
+
     pub fn task_exists(&self, id: usize) -> bool {
         id < self.len()
     }
@@ -143,18 +613,25 @@ impl Tasks {
         }
     }
 
-    pub fn push(&mut self, task: Task) {
+    pub fn push(&mut self, task: Task) -> Result<(), TasksError> {
+        let title = task.title.clone();
+
+        self.snapshot()?;
+
         if self.is_empty() {
             self.tasks = Some(vec![task]);
         } else {
             self.tasks.as_mut().unwrap().push(task);
         };
+
+        self.commit(&format!("Add task: {}", title))
     }
 
     pub fn remove(&mut self, id: usize) -> Result<(), TasksError> {
         if self.task_exists(id) {
+            self.snapshot()?;
             self.tasks.as_mut().unwrap().remove(id);
-            Ok(())
+            self.commit(&format!("Remove task {}", id))
         } else {
             Err(task_not_found(id))
         }
@@ -168,12 +645,81 @@ impl Tasks {
         }
     }
 
+    /// Returns all tasks (with their original ids) matching `predicate`.
+    pub fn filter<F>(&self, predicate: F) -> Vec<(usize, &Task)>
+    where
+        F: Fn(&Task) -> bool,
+    {
+        self.tasks
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, task)| predicate(task))
+            .collect()
+    }
+
+    /// Returns all tasks (with their original ids) tagged with `tag`.
+    pub fn filter_by_tag(&self, tag: &str) -> Vec<(usize, &Task)> {
+        self.filter(|task| {
+            task.tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(|t| t == tag))
+        })
+    }
+
+    /// Returns all tasks (with their original ids) with the given `status`.
+    pub fn filter_by_status(&self, status: &Status) -> Vec<(usize, &Task)> {
+        self.filter(|task| &task.status == status)
+    }
+
+    /// Collects the distinct, sorted set of tags in use across all tasks.
+    pub fn all_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .tasks
+            .iter()
+            .flatten()
+            .filter_map(|task| task.tags.as_ref())
+            .flatten()
+            .cloned()
+            .collect();
+
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Returns tasks (with their original ids) whose `deadline` or
+    /// `reminder` falls within `within` of now.
+    pub fn due(&self, within: Duration) -> Vec<(usize, &Task)> {
+        let now = Local::now().naive_local();
+        let cutoff = now + within;
+
+        self.filter(|task| {
+            task.deadline
+                .is_some_and(|deadline| deadline >= now && deadline <= cutoff)
+                || task
+                    .reminder
+                    .is_some_and(|reminder| reminder >= now && reminder <= cutoff)
+        })
+    }
+
+    /// Returns incomplete tasks (with their original ids) whose `deadline`
+    /// has already passed.
+    pub fn overdue(&self) -> Vec<(usize, &Task)> {
+        let now = Local::now().naive_local();
+
+        self.filter(|task| {
+            task.status != Status::Complete && task.deadline.is_some_and(|deadline| deadline < now)
+        })
+    }
+
     pub fn clear(&mut self) -> Result<(), TasksError> {
         if self.is_empty() {
             Err(no_tasks_available())
         } else {
+            self.snapshot()?;
             self.tasks = None;
-            Ok(())
+            self.commit("Clear all tasks")
         }
     }
 }
@@ -188,3 +734,167 @@ impl Status {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+    }
+
+    fn task(title: &str, status: Status, tags: Option<Vec<String>>) -> Task {
+        let mut task = Task::new(String::from(title), None, tags, None, None, None);
+        task.status = status;
+        task
+    }
+
+    fn temp_repo_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("agenda-test-{}-{}", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn recurrence_advances_daily_by_interval() {
+        let start = naive(2026, 1, 1, 9, 0, 0);
+        let next = Recurrence::Daily(3).advance(start).unwrap();
+        assert_eq!(next, naive(2026, 1, 4, 9, 0, 0));
+    }
+
+    #[test]
+    fn recurrence_advances_weekly_by_interval() {
+        let start = naive(2026, 1, 1, 9, 0, 0);
+        let next = Recurrence::Weekly(2).advance(start).unwrap();
+        assert_eq!(next, naive(2026, 1, 15, 9, 0, 0));
+    }
+
+    #[test]
+    fn recurrence_advances_monthly_by_interval() {
+        let start = naive(2026, 1, 31, 9, 0, 0);
+        let next = Recurrence::Monthly(1).advance(start).unwrap();
+        assert_eq!(next, naive(2026, 2, 28, 9, 0, 0));
+    }
+
+    #[test]
+    fn recurrence_advance_returns_none_on_overflow() {
+        let start = naive(2026, 1, 1, 9, 0, 0);
+        assert_eq!(Recurrence::Daily(u32::MAX).advance(start), None);
+        assert_eq!(Recurrence::Monthly(u32::MAX).advance(start), None);
+    }
+
+    #[test]
+    fn history_caps_past_at_limit() {
+        let path = temp_repo_path("history-cap");
+        let mut tasks = Tasks::new(&path, "tasks.json");
+
+        for i in 0..HISTORY_LIMIT + 5 {
+            tasks.tasks = Some(vec![task(&i.to_string(), Status::Inbox, None)]);
+            tasks.snapshot().unwrap();
+        }
+
+        let history = tasks.load_history().unwrap();
+        fs::remove_dir_all(&path).unwrap();
+
+        assert_eq!(history.past.len(), HISTORY_LIMIT);
+        // The oldest entries should have been dropped, leaving the tail.
+        let oldest_title = &history.past[0].as_ref().unwrap()[0].title;
+        assert_eq!(oldest_title, "5");
+    }
+
+    #[test]
+    fn snapshot_persists_history_to_disk_and_clears_future() {
+        let path = temp_repo_path("snapshot");
+        let mut tasks = Tasks::new(&path, "tasks.json");
+        tasks.tasks = Some(vec![task("a", Status::Inbox, None)]);
+        tasks
+            .save_history(&History {
+                past: Vec::new(),
+                future: vec![None],
+            })
+            .unwrap();
+
+        tasks.snapshot().unwrap();
+        tasks.tasks = Some(vec![task("a", Status::Active, None)]);
+        tasks.snapshot().unwrap();
+
+        let history = tasks.load_history().unwrap();
+        assert_eq!(history.past.len(), 2);
+        assert!(history.future.is_empty());
+
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn filter_by_tag_preserves_original_ids() {
+        let mut tasks = Tasks::new("", "");
+        tasks.tasks = Some(vec![
+            task("a", Status::Inbox, Some(vec![String::from("work")])),
+            task("b", Status::Inbox, Some(vec![String::from("home")])),
+            task("c", Status::Inbox, Some(vec![String::from("work")])),
+        ]);
+
+        let matches = tasks.filter_by_tag("work");
+        let ids: Vec<usize> = matches.iter().map(|(id, _)| *id).collect();
+
+        assert_eq!(ids, vec![0, 2]);
+    }
+
+    #[test]
+    fn filter_by_status_preserves_original_ids() {
+        let mut tasks = Tasks::new("", "");
+        tasks.tasks = Some(vec![
+            task("a", Status::Inbox, None),
+            task("b", Status::Complete, None),
+            task("c", Status::Complete, None),
+        ]);
+
+        let ids: Vec<usize> = tasks
+            .filter_by_status(&Status::Complete)
+            .iter()
+            .map(|(id, _)| *id)
+            .collect();
+
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn due_returns_tasks_within_window() {
+        let now = Local::now().naive_local();
+        let mut soon = task("soon", Status::Inbox, None);
+        soon.deadline = Some(now + Duration::hours(1));
+        let mut later = task("later", Status::Inbox, None);
+        later.deadline = Some(now + Duration::days(30));
+
+        let mut tasks = Tasks::new("", "");
+        tasks.tasks = Some(vec![soon, later]);
+
+        let ids: Vec<usize> = tasks
+            .due(Duration::hours(2))
+            .iter()
+            .map(|(id, _)| *id)
+            .collect();
+
+        assert_eq!(ids, vec![0]);
+    }
+
+    #[test]
+    fn overdue_ignores_complete_tasks() {
+        let now = Local::now().naive_local();
+        let mut overdue = task("overdue", Status::Inbox, None);
+        overdue.deadline = Some(now - Duration::days(1));
+        let mut done = task("done", Status::Complete, None);
+        done.deadline = Some(now - Duration::days(1));
+
+        let mut tasks = Tasks::new("", "");
+        tasks.tasks = Some(vec![overdue, done]);
+
+        let ids: Vec<usize> = tasks.overdue().iter().map(|(id, _)| *id).collect();
+
+        assert_eq!(ids, vec![0]);
+    }
+}